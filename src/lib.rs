@@ -63,8 +63,10 @@
 //! pub const _: *const () = (&<Foo<'static> as Tr<i32>>::foo) as *const _ as _;
 //! ```
 
+use std::collections::HashMap;
+
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::parse::Parse;
 use syn::parse::ParseStream;
 use syn::parse_macro_input;
@@ -72,7 +74,9 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::GenericParam;
 use syn::Ident;
+use syn::ImplItem;
 use syn::ItemFn;
+use syn::ItemImpl;
 use syn::Lifetime;
 use syn::Token;
 use syn::TypePath;
@@ -91,43 +95,489 @@ use syn::TypePath;
 /// fn foo<T>(t: T) {}
 /// ```
 ///
+/// Const generic parameters are spelled out the same way, e.g. `#[mono(N = 4)]` for
+/// `fn foo<const N: usize>(x: [u8; N])`.
+///
+/// Lifetime parameters are spelled out with a lifetime on both sides, e.g.
+/// `#[mono('a = 'static, T = i32)]` for `fn foo<'a, T>(t: &'a T) where 'a: 'a`, and are
+/// substituted into the turbofish in the same order they're declared. A key that names no
+/// lifetime, type, or const parameter of the function is a compile error. rustc rejects a
+/// late-bound lifetime (one that appears only in argument types) in a turbofish; the
+/// `where 'a: 'a` clause above makes `'a` early-bound.
+///
+/// A parameter may also be given a bracketed list of candidates, e.g.
+/// `#[mono(T = [i32, u8], U = [String, i64])]`, in which case every combination in the
+/// cartesian product of the lists is monomorphized, one pointer per combination.
+///
+/// Adding `export = "name"` emits, alongside the pointer cast, a `#[no_mangle] pub extern
+/// "C" fn name(...)` thin wrapper forwarding to the monomorphized instance, giving it a
+/// deterministic, linkable symbol (useful for FFI, or for `nm`/size tooling):
+/// ```rust,no_run
+/// use mono_macro::mono;
+/// #[mono(T = i32, export = "foo_i32")]
+/// fn foo<T>(t: T) -> T { t }
+/// ```
+/// expands to:
+/// ```rust,no_run
+/// pub const _: *const () = (&foo::<i32>) as *const _ as _;
+/// #[no_mangle]
+/// pub extern "C" fn foo_i32(arg0: i32) -> i32 {
+///     foo::<i32>(arg0)
+/// }
+/// fn foo<T>(t: T) -> T { t }
+/// ```
+///
+/// Applied to an `impl` block instead of a function, it force-monomorphizes every method
+/// in that block:
+/// ```rust,no_run
+/// use mono_macro::mono;
+/// struct Foo<T>(T);
+///
+/// #[mono(T = i32)]
+/// impl<T> Foo<T> {
+///     fn method(&self) {}
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn mono(attr: TokenStream, func: TokenStream) -> TokenStream {
+pub fn mono(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mono_attr = parse_macro_input!(attr as TypeEqs);
 
-    let input = func.clone();
+    if let Ok(item_impl) = syn::parse::<ItemImpl>(item.clone()) {
+        return mono_impl(&mono_attr, &item_impl, item);
+    }
+
+    let input = item.clone();
     let fn_sig = parse_macro_input!(input as ItemFn).sig;
     let fn_span = fn_sig.span();
     let func_ident = fn_sig.ident.clone();
 
-    let mut params = vec![];
-    for g in fn_sig.generics.params.into_iter() {
-        if let Some(t) = mono_attr
-            .eqs
-            .iter()
-            .find(|eq| match (&g, &eq.type_or_lifetime) {
-                // (GenericParam::Lifetime(ld), TypeOrLifetime::Lifetime(l)) => &ld.lifetime == l,
-                (GenericParam::Type(t1), TypeOrLifetime::Type(t2)) => &t1.ident == t2,
-                (_, _) => false,
-            })
-        {
-            params.push(t.param.clone());
-        } else if matches!(g, GenericParam::Type(_)) {
-            let err = syn::Error::new(fn_span, "all the type parameters should be spelled out")
-                .into_compile_error()
-                .into();
+    if let Some(err) = validate_keys(&mono_attr.eqs, fn_sig.generics.params.iter()) {
+        return err;
+    }
+
+    let mut param_keys = vec![];
+    let mut param_lists = vec![];
+    for g in &fn_sig.generics.params {
+        if let Some(list) = match_subst(&mono_attr.eqs, g) {
+            param_keys.push(generic_param_key(g));
+            param_lists.push(list.to_vec());
+        } else if matches!(g, GenericParam::Type(_) | GenericParam::Const(_)) {
+            let err = syn::Error::new(
+                fn_span,
+                "all the type and const parameters should be spelled out",
+            )
+            .into_compile_error()
+            .into();
             return err;
         }
     }
 
+    let combos = cartesian_product(param_lists);
+    let pointers = combos.iter().map(|params| {
+        quote! {
+            pub const _: *const () = (&#func_ident::<#(#params,)*>) as *const _ as _;
+        }
+    });
+
+    let wrapper = export_name(&mono_attr).map(|name| {
+        let export_ident = Ident::new(&name, fn_span);
+        let turbofish = combos.first().cloned().unwrap_or_default();
+        let subst: HashMap<String, TypeOrLifetime> =
+            param_keys.iter().cloned().zip(turbofish.iter().cloned()).collect();
+        export_wrapper(&fn_sig, &turbofish, &subst, &export_ident)
+    });
+
     let mut expand = TokenStream::from(quote! {
-        pub const _: *const () = (&#func_ident::<#(#params,)*>) as *const _ as _;
+        #(#pointers)*
+        #wrapper
     });
+    expand.extend(item);
+    expand
+}
+
+/// The key a generic parameter is matched against in a `#[mono(...)]` attribute.
+fn generic_param_key(g: &GenericParam) -> String {
+    match g {
+        GenericParam::Type(t) => t.ident.to_string(),
+        GenericParam::Const(c) => c.ident.to_string(),
+        GenericParam::Lifetime(l) => l.lifetime.ident.to_string(),
+    }
+}
+
+/// The string given to `export = "..."` in a `#[mono(...)]` attribute, if any.
+fn export_name(mono_attr: &TypeEqs) -> Option<String> {
+    mono_attr.eqs.iter().find_map(|eq| {
+        let TypeOrLifetime::Type(key) = &eq.type_or_lifetime else {
+            return None;
+        };
+        if key != "export" {
+            return None;
+        }
+        match eq.params.first() {
+            Some(TypeOrLifetime::Const(expr)) => match &**expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        }
+    })
+}
+
+/// Build a `#[no_mangle] pub extern "C" fn` that forwards to `fn_sig`'s monomorphized
+/// instance, with `subst` applied to each argument type and the return type.
+fn export_wrapper(
+    fn_sig: &syn::Signature,
+    turbofish: &[TypeOrLifetime],
+    subst: &HashMap<String, TypeOrLifetime>,
+    export_ident: &Ident,
+) -> TokenStream2 {
+    let func_ident = &fn_sig.ident;
+
+    let mut arg_idents = vec![];
+    let mut typed_args = vec![];
+    for (i, arg) in fn_sig.inputs.iter().enumerate() {
+        if let syn::FnArg::Typed(pat_ty) = arg {
+            // Fresh idents rather than the source pattern: the original may be a
+            // wildcard, a `mut`/`ref` binding, or a destructuring pattern, none of
+            // which are valid as a call argument.
+            let ident = format_ident!("arg{}", i);
+            let ty = substitute_type(&pat_ty.ty, subst);
+            typed_args.push(quote! { #ident: #ty });
+            arg_idents.push(ident);
+        }
+    }
+
+    let output = match &fn_sig.output {
+        syn::ReturnType::Default => quote! {},
+        syn::ReturnType::Type(arrow, ty) => {
+            let ty = substitute_type(ty, subst);
+            quote! { #arrow #ty }
+        }
+    };
+
+    quote! {
+        #[no_mangle]
+        pub extern "C" fn #export_ident(#(#typed_args),*) #output {
+            #func_ident::<#(#turbofish),*>(#(#arg_idents),*)
+        }
+    }
+}
+
+/// Substitute any generic parameter named in `subst` for its concrete type, recursing
+/// through the common compound type forms.
+fn substitute_type(ty: &syn::Type, subst: &HashMap<String, TypeOrLifetime>) -> syn::Type {
+    match ty {
+        syn::Type::Path(tp) => {
+            if tp.qself.is_none() && tp.path.segments.len() == 1 {
+                if let Some(TypeOrLifetime::Type(t)) =
+                    subst.get(&tp.path.segments[0].ident.to_string())
+                {
+                    return syn::parse_quote!(#t);
+                }
+            }
+            let mut tp = tp.clone();
+            for seg in tp.path.segments.iter_mut() {
+                if let syn::PathArguments::AngleBracketed(args) = &mut seg.arguments {
+                    for arg in args.args.iter_mut() {
+                        match arg {
+                            syn::GenericArgument::Type(t) => *t = substitute_type(t, subst),
+                            syn::GenericArgument::Const(e) => *e = substitute_expr(e, subst),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            syn::Type::Path(tp)
+        }
+        syn::Type::Reference(r) => {
+            let mut r = r.clone();
+            if let Some(lt) = &r.lifetime {
+                r.lifetime = match subst.get(&lt.ident.to_string()) {
+                    Some(TypeOrLifetime::Lifetime(l)) => Some(l.clone()),
+                    _ if lt.ident == "static" => Some(lt.clone()),
+                    // A lifetime with no concrete substitution has nothing to refer to
+                    // outside the original generic function, so elide it instead.
+                    _ => None,
+                };
+            }
+            r.elem = Box::new(substitute_type(&r.elem, subst));
+            syn::Type::Reference(r)
+        }
+        syn::Type::Slice(s) => {
+            let mut s = s.clone();
+            s.elem = Box::new(substitute_type(&s.elem, subst));
+            syn::Type::Slice(s)
+        }
+        syn::Type::Array(a) => {
+            let mut a = a.clone();
+            a.elem = Box::new(substitute_type(&a.elem, subst));
+            a.len = substitute_expr(&a.len, subst);
+            syn::Type::Array(a)
+        }
+        syn::Type::Tuple(t) => {
+            let mut t = t.clone();
+            for elem in t.elems.iter_mut() {
+                *elem = substitute_type(elem, subst);
+            }
+            syn::Type::Tuple(t)
+        }
+        syn::Type::Paren(p) => {
+            let mut p = p.clone();
+            p.elem = Box::new(substitute_type(&p.elem, subst));
+            syn::Type::Paren(p)
+        }
+        syn::Type::Ptr(p) => {
+            let mut p = p.clone();
+            p.elem = Box::new(substitute_type(&p.elem, subst));
+            syn::Type::Ptr(p)
+        }
+        _ => ty.clone(),
+    }
+}
 
-    expand.extend(func);
+/// Substitute a bare-ident expression named in `subst` (e.g. a const generic used as an
+/// array length) for its concrete value.
+fn substitute_expr(expr: &syn::Expr, subst: &HashMap<String, TypeOrLifetime>) -> syn::Expr {
+    if let syn::Expr::Path(ep) = expr {
+        if ep.qself.is_none() && ep.path.segments.len() == 1 {
+            if let Some(TypeOrLifetime::Const(c)) = subst.get(&ep.path.segments[0].ident.to_string())
+            {
+                return (**c).clone();
+            }
+        }
+    }
+    expr.clone()
+}
+
+/// Reject an attribute key (other than the special `export = "..."`) that names none of
+/// `params`, with a spanned error pointing at the offending key.
+fn validate_keys<'a>(
+    eqs: &Punctuated<TypeEqTo, Token![,]>,
+    params: impl Iterator<Item = &'a GenericParam>,
+) -> Option<TokenStream> {
+    let params: Vec<&GenericParam> = params.collect();
+    for eq in eqs {
+        if let TypeOrLifetime::Type(key) = &eq.type_or_lifetime {
+            if key == "export" {
+                continue;
+            }
+        }
+        let known = params.iter().any(|g| match (*g, &eq.type_or_lifetime) {
+            (GenericParam::Lifetime(ld), TypeOrLifetime::Lifetime(l)) => &ld.lifetime == l,
+            (GenericParam::Type(t1), TypeOrLifetime::Type(t2)) => &t1.ident == t2,
+            (GenericParam::Const(c), TypeOrLifetime::Type(t2)) => &c.ident == t2,
+            (_, _) => false,
+        });
+        if !known {
+            return Some(
+                syn::Error::new(
+                    eq.type_or_lifetime.span(),
+                    "no matching lifetime, type or const parameter for this key",
+                )
+                .into_compile_error()
+                .into(),
+            );
+        }
+    }
+    None
+}
+
+/// Find the substitution candidates the attribute gives for a single generic parameter
+/// of the annotated item, if any.
+fn match_subst<'a>(
+    eqs: &'a Punctuated<TypeEqTo, Token![,]>,
+    g: &GenericParam,
+) -> Option<&'a [TypeOrLifetime]> {
+    eqs.iter()
+        .find(|eq| match (g, &eq.type_or_lifetime) {
+            (GenericParam::Lifetime(ld), TypeOrLifetime::Lifetime(l)) => &ld.lifetime == l,
+            (GenericParam::Type(t1), TypeOrLifetime::Type(t2)) => &t1.ident == t2,
+            (GenericParam::Const(c), TypeOrLifetime::Type(t2)) => &c.ident == t2,
+            (_, _) => false,
+        })
+        .map(|eq| eq.params.as_slice())
+}
+
+/// The cartesian product of a list of candidate lists, in the same order as `lists`.
+/// An empty input yields a single empty combination, so callers with no generics to
+/// substitute still get exactly one iteration.
+fn cartesian_product(lists: Vec<Vec<TypeOrLifetime>>) -> Vec<Vec<TypeOrLifetime>> {
+    lists.into_iter().fold(vec![vec![]], |acc, list| {
+        acc.into_iter()
+            .flat_map(|prefix| {
+                list.iter().map(move |item| {
+                    let mut combo = prefix.clone();
+                    combo.push(item.clone());
+                    combo
+                })
+            })
+            .collect()
+    })
+}
+
+/// `#[mono]` applied to an `impl` block: emit one monomorphizing pointer per method
+/// per combination in the cartesian product of the attribute's substitutions.
+fn mono_impl(mono_attr: &TypeEqs, item_impl: &ItemImpl, original: TokenStream) -> TokenStream {
+    let impl_span = item_impl.span();
+
+    let method_generics = item_impl.items.iter().filter_map(|item| match item {
+        ImplItem::Method(method) => Some(method.sig.generics.params.iter()),
+        _ => None,
+    });
+    if let Some(err) = validate_keys(
+        &mono_attr.eqs,
+        item_impl.generics.params.iter().chain(method_generics.flatten()),
+    ) {
+        return err;
+    }
+
+    let mut subst_keys = vec![];
+    let mut subst_lists = vec![];
+    for g in &item_impl.generics.params {
+        match g {
+            GenericParam::Lifetime(ld) => match match_subst(&mono_attr.eqs, g) {
+                Some(list) => {
+                    subst_keys.push(ld.lifetime.ident.to_string());
+                    subst_lists.push(list.to_vec());
+                }
+                None => {
+                    return syn::Error::new(
+                        impl_span,
+                        "all the lifetime parameters should be spelled out",
+                    )
+                    .into_compile_error()
+                    .into();
+                }
+            },
+            GenericParam::Type(t) => match match_subst(&mono_attr.eqs, g) {
+                Some(list) => {
+                    subst_keys.push(t.ident.to_string());
+                    subst_lists.push(list.to_vec());
+                }
+                None => {
+                    return syn::Error::new(impl_span, "all the type parameters should be spelled out")
+                        .into_compile_error()
+                        .into();
+                }
+            },
+            GenericParam::Const(c) => match match_subst(&mono_attr.eqs, g) {
+                Some(list) => {
+                    subst_keys.push(c.ident.to_string());
+                    subst_lists.push(list.to_vec());
+                }
+                None => {
+                    return syn::Error::new(impl_span, "all the const parameters should be spelled out")
+                        .into_compile_error()
+                        .into();
+                }
+            },
+        }
+    }
+
+    let self_ty_path = match &*item_impl.self_ty {
+        syn::Type::Path(tp) => tp,
+        _ => {
+            return syn::Error::new(impl_span, "#[mono] on an impl only supports a path self type")
+                .into_compile_error()
+                .into();
+        }
+    };
+
+    let mut pointers = vec![];
+    for combo in cartesian_product(subst_lists) {
+        let subst: HashMap<String, TypeOrLifetime> =
+            subst_keys.iter().cloned().zip(combo).collect();
+
+        let self_base = bare_path(&self_ty_path.path);
+        let self_args = turbofish_for(&self_ty_path.path, &subst);
+
+        let trait_ref = item_impl.trait_.as_ref().map(|(_, path, _)| {
+            let base = bare_path(path);
+            let args = turbofish_for(path, &subst);
+            (base, args)
+        });
+
+        for item in &item_impl.items {
+            let ImplItem::Method(method) = item else {
+                continue;
+            };
+            let method_ident = &method.sig.ident;
+
+            let mut own_lists = vec![];
+            let mut skip = false;
+            for g in &method.sig.generics.params {
+                if let Some(list) = match_subst(&mono_attr.eqs, g) {
+                    own_lists.push(list.to_vec());
+                } else if matches!(g, GenericParam::Type(_) | GenericParam::Const(_)) {
+                    skip = true;
+                    break;
+                }
+            }
+            if skip {
+                continue;
+            }
+
+            for own_params in cartesian_product(own_lists) {
+                let method_turbofish = if own_params.is_empty() {
+                    quote! {}
+                } else {
+                    quote! { ::<#(#own_params),*> }
+                };
+
+                let pointer = if let Some((trait_base, trait_args)) = &trait_ref {
+                    quote! {
+                        pub const _: *const () =
+                            (&<#self_base #self_args as #trait_base #trait_args>::#method_ident #method_turbofish) as *const _ as _;
+                    }
+                } else {
+                    quote! {
+                        pub const _: *const () =
+                            (&#self_base #self_args::#method_ident #method_turbofish) as *const _ as _;
+                    }
+                };
+                pointers.push(pointer);
+            }
+        }
+    }
+
+    let mut expand = TokenStream::from(quote! { #(#pointers)* });
+    expand.extend(original);
     expand
 }
 
+/// Clone `path` with all generic arguments stripped from every segment.
+fn bare_path(path: &syn::Path) -> TokenStream2 {
+    let mut path = path.clone();
+    for seg in path.segments.iter_mut() {
+        seg.arguments = syn::PathArguments::None;
+    }
+    quote! { #path }
+}
+
+/// Build the turbofish for `path`'s last segment, substituting any generic argument
+/// that names one of `subst`'s keys and leaving the rest untouched.
+fn turbofish_for(path: &syn::Path, subst: &HashMap<String, TypeOrLifetime>) -> TokenStream2 {
+    let args = match path.segments.last().map(|s| &s.arguments) {
+        Some(syn::PathArguments::AngleBracketed(a)) => a,
+        _ => return quote! {},
+    };
+    let mapped = args.args.iter().map(|arg| match arg {
+        syn::GenericArgument::Type(t) => {
+            let t = substitute_type(t, subst);
+            quote! { #t }
+        }
+        syn::GenericArgument::Lifetime(lt) => match subst.get(&lt.ident.to_string()) {
+            Some(t) => quote! { #t },
+            None => quote! { #lt },
+        },
+        other => quote! { #other },
+    });
+    quote! { ::<#(#mapped),*> }
+}
+
 /// Force monomorphizing on a path of function, for the complex functions like impl methods of generic types.
 /// For example,
 /// ```rust,no_run
@@ -155,12 +605,55 @@ pub fn mono(attr: TokenStream, func: TokenStream) -> TokenStream {
 /// }
 /// pub const _: *const () = (&<Foo<i32> as Trait<u8>>::method) as *const _ as _;
 /// ```
+///
+/// A second, string-literal argument additionally exports the pointer under a
+/// deterministic, `#[no_mangle]` symbol name, e.g.
+/// `mono_macro!(<Foo<i32> as Trait<u8>>::method, "foo_i32_method")`, alongside the
+/// usual anonymous `const _` cast.
+///
+/// Note this is a data symbol (`pub static NAME: AtomicPtr<()>` holding the function
+/// pointer), not a forwarding function: a bare path carries no argument list or return
+/// type to reconstruct a callable wrapper from, unlike `#[mono(.., export = "...")]`
+/// on a function item, which has the original signature available.
 #[proc_macro]
 pub fn mono_macro(input: TokenStream) -> TokenStream {
-    let path = parse_macro_input!(input as TypePath);
-    TokenStream::from(quote! {
+    let MonoMacroInput { path, name } = parse_macro_input!(input as MonoMacroInput);
+
+    let pointer_cast = quote! {
         pub const _: *const () = (&#path) as *const _ as _;
-    })
+    };
+
+    match name {
+        Some(name) => {
+            let export_ident = Ident::new(&name.value(), name.span());
+            TokenStream::from(quote! {
+                #pointer_cast
+                #[no_mangle]
+                pub static #export_ident: ::core::sync::atomic::AtomicPtr<()> =
+                    ::core::sync::atomic::AtomicPtr::new((&#path) as *const _ as *mut _);
+            })
+        }
+        None => TokenStream::from(pointer_cast),
+    }
+}
+
+/// `mono_macro!(path)` or `mono_macro!(path, "exported_name")`.
+struct MonoMacroInput {
+    path: TypePath,
+    name: Option<syn::LitStr>,
+}
+
+impl Parse for MonoMacroInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let name = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(MonoMacroInput { path, name })
+    }
 }
 
 // T = i32, U = i64
@@ -176,20 +669,31 @@ impl Parse for TypeEqs {
     }
 }
 
-// T = i32
+// T = i32, or T = [i32, u8] for a cartesian product of candidates
 struct TypeEqTo {
     type_or_lifetime: TypeOrLifetime,
     #[allow(dead_code)]
     eq_token: Token![=],
-    param: TypeOrLifetime,
+    params: Vec<TypeOrLifetime>,
 }
 
 impl Parse for TypeEqTo {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let type_or_lifetime = input.parse()?;
+        let eq_token = input.parse()?;
+        let params = if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            Punctuated::<TypeOrLifetime, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect()
+        } else {
+            vec![input.parse()?]
+        };
         Ok(TypeEqTo {
-            type_or_lifetime: input.parse()?,
-            eq_token: input.parse()?,
-            param: input.parse()?,
+            type_or_lifetime,
+            eq_token,
+            params,
         })
     }
 }
@@ -198,6 +702,7 @@ impl Parse for TypeEqTo {
 enum TypeOrLifetime {
     Type(Ident),
     Lifetime(syn::Lifetime),
+    Const(Box<syn::Expr>),
 }
 
 impl Parse for TypeOrLifetime {
@@ -205,6 +710,19 @@ impl Parse for TypeOrLifetime {
         let lookahead = input.lookahead1();
         if lookahead.peek(Lifetime) {
             input.parse().map(TypeOrLifetime::Lifetime)
+        } else if lookahead.peek(syn::Lit) {
+            input.parse::<syn::Lit>().map(|lit| {
+                TypeOrLifetime::Const(Box::new(syn::Expr::Lit(syn::ExprLit {
+                    attrs: vec![],
+                    lit,
+                })))
+            })
+        } else if lookahead.peek(syn::token::Brace) {
+            let content;
+            syn::braced!(content in input);
+            content
+                .parse()
+                .map(|e| TypeOrLifetime::Const(Box::new(e)))
         } else if lookahead.peek(Ident) {
             input.parse().map(TypeOrLifetime::Type)
         } else {
@@ -220,6 +738,17 @@ impl quote::ToTokens for TypeOrLifetime {
         match self {
             TypeOrLifetime::Lifetime(l) => l.to_tokens(tokens),
             TypeOrLifetime::Type(t) => t.to_tokens(tokens),
+            TypeOrLifetime::Const(e) => e.to_tokens(tokens),
+        }
+    }
+}
+
+impl TypeOrLifetime {
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            TypeOrLifetime::Lifetime(l) => l.span(),
+            TypeOrLifetime::Type(t) => t.span(),
+            TypeOrLifetime::Const(e) => e.span(),
         }
     }
 }
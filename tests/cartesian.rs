@@ -0,0 +1,7 @@
+use mono_macro::mono;
+
+#[test]
+fn test_cartesian_product() {
+    #[mono(T = [i32, u8], U = [String, i64])]
+    fn foo<T, U>(_t: T, _u: U) {}
+}
@@ -0,0 +1,17 @@
+use mono_macro::mono;
+
+#[test]
+fn test_const_generic() {
+    #[mono(N = 4)]
+    fn foo<const N: usize>(_x: [u8; N]) {}
+}
+
+#[test]
+fn test_const_generic_export() {
+    #[mono(N = 4, export = "foo4")]
+    fn foo<const N: usize>(x: &[u8; N]) -> usize {
+        x.len()
+    }
+
+    assert_eq!(foo4(&[0u8; 4]), 4);
+}
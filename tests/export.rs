@@ -0,0 +1,52 @@
+use mono_macro::mono;
+use mono_macro::mono_macro;
+
+#[test]
+fn test_export_wrapper() {
+    #[mono(T = i32, export = "foo_i32")]
+    fn foo<T>(t: T) -> T {
+        t
+    }
+
+    assert_eq!(foo_i32(42), 42);
+}
+
+#[test]
+fn test_mono_macro_export() {
+    struct Foo<T>(T);
+    trait Trait<K> {
+        fn method(&self, k: K) -> K {
+            k
+        }
+    }
+    impl<T, K> Trait<K> for Foo<T> {
+        fn method(&self, k: K) -> K {
+            k
+        }
+    }
+
+    mono_macro!(<Foo<i32> as Trait<u8>>::method, "foo_trait_method");
+}
+
+#[test]
+fn test_export_wrapper_generic_container() {
+    #[mono(T = i32, export = "foo_vec_i32")]
+    #[allow(clippy::ptr_arg)]
+    fn foo<T>(t: &Vec<T>) -> usize {
+        t.len()
+    }
+
+    assert_eq!(foo_vec_i32(&vec![1, 2, 3]), 3);
+}
+
+#[test]
+fn test_export_wrapper_non_ident_patterns() {
+    #[mono(T = i32, export = "foo_wildcard")]
+    #[allow(clippy::toplevel_ref_arg)]
+    fn foo<T>(_: T, mut n: i32, ref r: i32) -> i32 {
+        n += 1;
+        n + *r
+    }
+
+    assert_eq!(foo_wildcard(0, 10, 1), 12);
+}
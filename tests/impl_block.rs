@@ -0,0 +1,50 @@
+use mono_macro::mono;
+
+#[test]
+fn test_inherent_impl() {
+    struct Foo<T>(T);
+
+    #[mono(T = i32)]
+    impl<T> Foo<T> {
+        #[allow(dead_code)]
+        fn method(&self) {}
+    }
+}
+
+#[test]
+fn test_trait_impl() {
+    struct Foo<T>(T);
+
+    trait Trait<K> {
+        fn method(&self, _k: K) {}
+    }
+
+    #[mono(T = i32, K = u8)]
+    impl<T, K> Trait<K> for Foo<T> {
+        fn method(&self, _k: K) {}
+    }
+}
+
+#[test]
+fn test_inherent_impl_generic_container() {
+    #[allow(dead_code)]
+    struct Foo<T>(Vec<T>);
+
+    #[mono(T = i32)]
+    impl<T> Foo<Vec<T>> {
+        #[allow(dead_code)]
+        fn method(&self) {}
+    }
+}
+
+#[test]
+fn test_impl_with_lifetime() {
+    #[allow(dead_code)]
+    struct Foo<'a, T>(&'a T);
+
+    #[mono('a = 'static, T = i32)]
+    impl<'a, T> Foo<'a, T> {
+        #[allow(dead_code)]
+        fn method(&self) {}
+    }
+}
@@ -0,0 +1,24 @@
+use mono_macro::mono;
+
+#[test]
+fn test_lifetime_and_type() {
+    #[mono('a = 'static, T = i32)]
+    fn foo<'a, T>(_t: &'a T)
+    where
+        'a: 'a,
+    {
+    }
+}
+
+#[test]
+fn test_lifetime_export() {
+    #[mono('a = 'static, T = i32, export = "foo_export")]
+    fn foo<'a, T>(t: &'a T) -> &'a T
+    where
+        'a: 'a,
+    {
+        t
+    }
+
+    assert_eq!(*foo_export(&42), 42);
+}